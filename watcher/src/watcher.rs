@@ -12,26 +12,363 @@ use mc_common::{
     logger::{log, Logger},
     HashMap, HashSet,
 };
+use mc_crypto_keys::Ed25519Public;
 use mc_ledger_db::Ledger;
 use mc_ledger_sync::ReqwestTransactionsFetcher;
-use mc_transaction_core::BlockData;
+use mc_transaction_core::{BlockData, BlockID};
 
 use std::{
+    collections::VecDeque,
     iter::FromIterator,
+    ops::Range,
     sync::{
         atomic::{AtomicBool, Ordering},
-        Arc,
+        mpsc::{self, Receiver, Sender},
+        Arc, Mutex,
     },
     thread,
-    time::Duration,
+    time::{Duration, Instant},
 };
 use url::Url;
 
+/// Number of consecutive signature-verification failures from a source
+/// before it is quarantined (excluded from the parallel-fetch set for a
+/// backoff window).
+const QUARANTINE_FAILURE_THRESHOLD: u32 = 3;
+
+/// How long a source stays quarantined before it is retried.
+const QUARANTINE_BACKOFF: Duration = Duration::from_secs(5 * 60);
+
+/// Smoothing factor for the fetch-latency exponential moving average.
+/// Closer to 1.0 weighs recent fetches more heavily.
+const LATENCY_EMA_ALPHA: f64 = 0.2;
+
+/// Initial (and minimum) backoff window applied to a source after a fetch
+/// error, before doubling on repeated errors.
+const MIN_SOURCE_BACKOFF: Duration = Duration::from_secs(1);
+
+/// The backoff window for a repeatedly-erroring source is capped here so it
+/// is always retried eventually.
+const MAX_SOURCE_BACKOFF: Duration = Duration::from_secs(5 * 60);
+
+/// Tracks signature-verification and fetch health for a single source url,
+/// used to quarantine mirrors that serve bad signatures and to route fetch
+/// traffic away from slow or flaky ones.
+#[derive(Clone, Debug)]
+struct SourceHealth {
+    /// Consecutive signature-verification failures from this source.
+    consecutive_failures: u32,
+    /// If set, this source is quarantined until this instant.
+    quarantined_until: Option<Instant>,
+    /// Exponential moving average fetch latency, in milliseconds.
+    avg_latency_millis: f64,
+    /// Total fetch attempts observed, used together with `failure_count` to
+    /// derive a rolling success rate.
+    attempt_count: u64,
+    /// Total fetch failures observed (network errors, timeouts, 404s, etc,
+    /// as opposed to signature-verification failures).
+    failure_count: u64,
+    /// Consecutive fetch errors since the last successful fetch.
+    recent_error_count: u32,
+    /// If set, this source is backed off (skipped when routing work) until
+    /// this instant.
+    backoff_until: Option<Instant>,
+    /// The backoff duration to use the next time this source errors;
+    /// doubles on each consecutive failure up to `MAX_SOURCE_BACKOFF`.
+    next_backoff: Duration,
+}
+
+impl Default for SourceHealth {
+    fn default() -> Self {
+        Self {
+            consecutive_failures: 0,
+            quarantined_until: None,
+            avg_latency_millis: 0.0,
+            attempt_count: 0,
+            failure_count: 0,
+            recent_error_count: 0,
+            backoff_until: None,
+            next_backoff: MIN_SOURCE_BACKOFF,
+        }
+    }
+}
+
+impl SourceHealth {
+    /// Record a successful fetch, resetting the error streak and backoff,
+    /// and folding the observed latency into the rolling average.
+    fn record_success(&mut self, latency: Duration) {
+        self.attempt_count += 1;
+        self.recent_error_count = 0;
+        self.backoff_until = None;
+        self.next_backoff = MIN_SOURCE_BACKOFF;
+
+        let latency_millis = latency.as_secs_f64() * 1000.0;
+        self.avg_latency_millis = if self.attempt_count <= 1 {
+            latency_millis
+        } else {
+            (LATENCY_EMA_ALPHA * latency_millis)
+                + ((1.0 - LATENCY_EMA_ALPHA) * self.avg_latency_millis)
+        };
+    }
+
+    /// Record a failed fetch, growing the backoff window exponentially so
+    /// repeatedly-erroring sources are queried less and less often.
+    fn record_failure(&mut self) {
+        self.attempt_count += 1;
+        self.failure_count += 1;
+        self.recent_error_count += 1;
+        self.backoff_until = Some(Instant::now() + self.next_backoff);
+        self.next_backoff = (self.next_backoff * 2).min(MAX_SOURCE_BACKOFF);
+    }
+
+    /// Fraction of fetches from this source that succeeded, in [0, 1].
+    /// Sources with no recorded attempts are assumed healthy.
+    fn success_rate(&self) -> f64 {
+        if self.attempt_count == 0 {
+            1.0
+        } else {
+            1.0 - (self.failure_count as f64 / self.attempt_count as f64)
+        }
+    }
+
+    /// How many requests to keep outstanding at once when pipelining a
+    /// range fetch from this source, out of `max_window`. A source with any
+    /// error since its last success is throttled down to a single in-flight
+    /// request (just enough to probe whether it has recovered) so it can't
+    /// compete for fetch capacity with reliable sources; otherwise the
+    /// window scales with its rolling success rate, giving the most
+    /// reliable sources first claim on pipeline depth and routing fetch
+    /// traffic away from flaky ones without waiting for them to be
+    /// quarantined outright.
+    fn preferred_fetch_window(&self, max_window: usize) -> usize {
+        if self.recent_error_count > 0 {
+            return MIN_FETCH_WINDOW;
+        }
+        let scaled = (max_window as f64 * self.success_rate()).round() as usize;
+        scaled.clamp(MIN_FETCH_WINDOW, max_window)
+    }
+}
+
+/// The narrowest a source's pipeline window is ever throttled to; a source
+/// this flaky is still retried every round (as opposed to being quarantined
+/// or backed off) so it's promoted back to full speed as soon as it proves
+/// reliable again.
+const MIN_FETCH_WINDOW: usize = 1;
+
+/// The default number of sources that must report matching block contents
+/// before a block is considered confirmed and the watcher advances past it.
+/// With only one source configured this degrades to trusting it outright,
+/// which matches the historical (pre-quorum) behavior.
+const DEFAULT_QUORUM_THRESHOLD: usize = 1;
+
+/// How many blocks back from the current sync height (or the ledger height,
+/// whichever is higher) `Watcher::status` scans
+/// the DB for divergence events. Disputes older than this are still reported
+/// as long as they remain unresolved, via the in-memory `pending_tallies`
+/// rather than a DB scan, so this only bounds the cost of re-discovering
+/// divergences that were already resolved by the time `status` is called.
+const STATUS_DIVERGENCE_SCAN_WINDOW: u64 = 10_000;
+
+/// A single block index's report of contents/signer from each source that
+/// has reported on it so far. Unlike a single `sync_blocks` round, this
+/// accumulates across rounds (see `Watcher::pending_tallies`) so a source
+/// that falls behind and stops being asked about an index doesn't cause the
+/// watcher to forget what it had previously reported.
+#[derive(Clone, Debug)]
+struct BlockTally {
+    /// Maps a block contents id to the set of source urls that reported it.
+    by_content: HashMap<BlockID, HashSet<Url>>,
+    /// Maps a signer's public key bytes to the set of source urls that
+    /// reported a signature from that signer.
+    by_signer: HashMap<Vec<u8>, HashSet<Url>>,
+}
+
+impl Default for BlockTally {
+    fn default() -> Self {
+        Self {
+            by_content: HashMap::default(),
+            by_signer: HashMap::default(),
+        }
+    }
+}
+
+impl BlockTally {
+    /// Record (or re-record) one source's report for this block index.
+    /// Idempotent: reporting the same content/signer for the same url twice
+    /// (e.g. because the source was re-polled across rounds) does not
+    /// inflate the tally.
+    fn observe(&mut self, src_url: Url, content_id: BlockID, signer: Option<Vec<u8>>) {
+        self.by_content
+            .entry(content_id)
+            .or_insert_with(HashSet::default)
+            .insert(src_url.clone());
+        if let Some(signer) = signer {
+            self.by_signer
+                .entry(signer)
+                .or_insert_with(HashSet::default)
+                .insert(src_url);
+        }
+    }
+
+    /// The content id reported by the largest group of sources (more than
+    /// any other content reported for this index, though not necessarily a
+    /// majority of all sources), along with how many
+    /// sources agreed on it. Returns `None` if there is no single largest
+    /// group, e.g. an exact tie between two distinct contents, so a genuine
+    /// split stays pending instead of being committed based on arbitrary
+    /// `HashMap` iteration order.
+    fn quorum_content(&self) -> Option<(&BlockID, usize)> {
+        let mut best: Option<(&BlockID, usize)> = None;
+        let mut tied = false;
+
+        for (content_id, urls) in &self.by_content {
+            let count = urls.len();
+            match best {
+                Some((_, best_count)) if count > best_count => {
+                    best = Some((content_id, count));
+                    tied = false;
+                }
+                Some((_, best_count)) if count == best_count => tied = true,
+                None => best = Some((content_id, count)),
+                _ => {}
+            }
+        }
+
+        if tied {
+            None
+        } else {
+            best
+        }
+    }
+
+    /// Whether any two sources disagreed about this block's contents or
+    /// signer.
+    fn is_divergent(&self) -> bool {
+        self.by_content.len() > 1 || self.by_signer.len() > 1
+    }
+}
+
+/// A recorded disagreement between sources about the contents or signer of
+/// a block at a given index, e.g. a validator equivocating or an archive
+/// mirror serving a forked history.
+#[derive(Clone, Debug)]
+pub struct DivergenceEvent {
+    /// The block index the sources disagreed about.
+    pub block_index: u64,
+    /// The source that reported this particular observation.
+    pub src_url: Url,
+    /// The block contents id this source reported.
+    pub content_id: BlockID,
+    /// The signer public key bytes this source's signature was from, if any.
+    pub signer: Option<Vec<u8>>,
+}
+
+/// A point-in-time snapshot of a source's observed reliability and
+/// performance, used to route fetch traffic toward faster/more-reliable
+/// mirrors and away from slow or flaky ones.
+#[derive(Clone, Debug)]
+pub struct SourceMetrics {
+    /// Exponential moving average fetch latency, in milliseconds.
+    pub avg_latency_millis: f64,
+    /// Fraction of fetches from this source that succeeded, in [0, 1].
+    pub success_rate: f64,
+    /// Consecutive fetch errors since the last successful fetch.
+    pub recent_error_count: u32,
+    /// If set, this source is backed off until this instant and is skipped
+    /// when routing work.
+    pub backoff_until: Option<Instant>,
+}
+
+/// The subset of a source's metrics that are persisted to the watcher DB, so
+/// a restarted watcher doesn't have to re-learn which mirrors are slow or
+/// flaky from scratch. Backoff/quarantine state is intentionally excluded;
+/// it is re-derived quickly from a handful of fetches after a restart.
+#[derive(Clone, Debug, Default)]
+pub struct PersistedSourceMetrics {
+    /// Exponential moving average fetch latency, in milliseconds.
+    pub avg_latency_millis: f64,
+    /// Total fetch attempts observed.
+    pub attempt_count: u64,
+    /// Total fetch failures observed.
+    pub failure_count: u64,
+}
+
+/// A structured snapshot of watcher health. Modeled on a status RPC that
+/// reports sync height plus node identity, this turns the watcher from an
+/// opaque background thread into something observable for ops dashboards
+/// and alerting.
+#[derive(Clone, Debug)]
+pub struct WatcherStatus {
+    /// The last synced block index for each configured source url, or None
+    /// if nothing has been synced from it yet.
+    pub last_synced_blocks: HashMap<Url, Option<u64>>,
+    /// The full set of source urls this watcher instance is configured to
+    /// track, so external monitoring can confirm which mirrors a given
+    /// watcher is actually watching.
+    pub source_urls: HashSet<Url>,
+    /// The lowest next block this watcher still needs to sync.
+    pub lowest_next_block_to_sync: u64,
+    /// The ledger block height this status was measured against.
+    pub ledger_num_blocks: u64,
+    /// Whether the watcher is behind the ledger.
+    pub is_behind: bool,
+    /// Source urls currently quarantined due to signature-verification
+    /// failures.
+    pub quarantined_sources: Vec<Url>,
+    /// Observed latency/reliability metrics for each source url.
+    pub source_health: HashMap<Url, SourceMetrics>,
+    /// Block indices at which sources reported differing contents or
+    /// signers: every divergence recorded within the last
+    /// `STATUS_DIVERGENCE_SCAN_WINDOW` blocks, resolved or not, plus any
+    /// older one that is still unresolved.
+    pub diverged_block_indices: Vec<u64>,
+    /// A content/version identifier for the watcher DB, so external
+    /// monitoring can confirm which on-disk DB a given watcher instance is
+    /// actually reading from.
+    pub db_version: u64,
+}
+
 /// Watches multiple consensus validators and collects block signatures.
 pub struct Watcher {
     transactions_fetcher: Arc<ReqwestTransactionsFetcher>,
     watcher_db: WatcherDB,
     store_block_data: bool,
+    /// The number of sources that must agree on a block's contents before
+    /// it is confirmed and the watcher advances past it. Until then the
+    /// block is held in a pending/disputed state.
+    quorum_threshold: usize,
+    /// The set of consensus signer public keys whose block signatures we
+    /// trust. Signatures from any other signer are rejected.
+    allowed_signer_keys: HashSet<Ed25519Public>,
+    /// Per-source signature-verification health, used to quarantine
+    /// misbehaving archive mirrors.
+    source_health: Arc<Mutex<HashMap<Url, SourceHealth>>>,
+    /// Block indices that have been reported on by at least one source but
+    /// have not yet reached `quorum_threshold` agreement, keyed by block
+    /// index. Kept across `sync_blocks` rounds (instead of being rebuilt
+    /// from only the current round's reports) so that once the majority of
+    /// sources advance past a disputed index, a straggler reporting on it
+    /// alone in a later round can't trivially "agree with itself" and have
+    /// previously-flagged divergent content silently committed. Once an
+    /// index reaches quorum and every source that agreed on it has committed
+    /// it, it is removed from here; see `resolved_blocks`
+    /// for what happens to a source that keeps disagreeing with it
+    /// afterwards.
+    pending_tallies: Arc<Mutex<HashMap<u64, BlockTally>>>,
+    /// The confirmed content id for every block index that was disputed
+    /// (more than one content reported) before it resolved. Lets a source
+    /// that keeps reporting a different content at an already-resolved
+    /// index be recognized and logged without resurrecting the index as
+    /// pending and dragging every other (already-advanced) source back to
+    /// re-fetch it. Indices that resolved unanimously — the overwhelming
+    /// common case — are never added here, so this only grows with actual
+    /// equivocation incidents, not chain height.
+    resolved_blocks: Arc<Mutex<HashMap<u64, BlockID>>>,
+    /// Source/block-index pairs whose divergence has already been recorded,
+    /// so a source that keeps disagreeing at the same index — whether
+    /// still pending or already resolved — doesn't write a duplicate
+    /// divergence event to the DB every round.
+    recorded_divergences: Arc<Mutex<HashSet<(u64, Url)>>>,
     logger: Logger,
 }
 
@@ -44,11 +381,46 @@ impl Watcher {
     /// * `transactions_fetcher` - The transaction fetcher used to fetch blocks
     ///   from watched source URLs
     /// * `store_block_data` - The fetched BlockData objects into the database
+    /// * `allowed_signer_keys` - The set of consensus signer public keys
+    ///   whose block signatures are trusted
     /// * `logger` - Logger
     pub fn new(
         watcher_db: WatcherDB,
         transactions_fetcher: ReqwestTransactionsFetcher,
         store_block_data: bool,
+        allowed_signer_keys: HashSet<Ed25519Public>,
+        logger: Logger,
+    ) -> Self {
+        Self::with_quorum_threshold(
+            watcher_db,
+            transactions_fetcher,
+            store_block_data,
+            allowed_signer_keys,
+            DEFAULT_QUORUM_THRESHOLD,
+            logger,
+        )
+    }
+
+    /// Create a new Watcher that requires `quorum_threshold` sources to
+    /// agree on a block's contents before it is considered confirmed.
+    ///
+    /// # Arguments
+    /// * `watcher_db` - The backing database to use for storing and retreiving
+    ///   data
+    /// * `transactions_fetcher` - The transaction fetcher used to fetch blocks
+    ///   from watched source URLs
+    /// * `store_block_data` - The fetched BlockData objects into the database
+    /// * `allowed_signer_keys` - The set of consensus signer public keys
+    ///   whose block signatures are trusted
+    /// * `quorum_threshold` - The number of sources that must agree on a
+    ///   block's contents before it is confirmed
+    /// * `logger` - Logger
+    pub fn with_quorum_threshold(
+        watcher_db: WatcherDB,
+        transactions_fetcher: ReqwestTransactionsFetcher,
+        store_block_data: bool,
+        allowed_signer_keys: HashSet<Ed25519Public>,
+        quorum_threshold: usize,
         logger: Logger,
     ) -> Self {
         // Sanity check that the watcher db and transaction fetcher were initialized
@@ -63,14 +435,343 @@ impl Watcher {
             )
         );
 
+        // Seed per-source metrics from whatever was persisted by a previous run, so
+        // a restarted watcher doesn't have to re-learn which mirrors are slow or
+        // flaky from scratch.
+        let source_health = watcher_db
+            .get_source_metrics()
+            .expect("get_source_metrics failed")
+            .into_iter()
+            .map(|(src_url, persisted)| {
+                (
+                    src_url,
+                    SourceHealth {
+                        avg_latency_millis: persisted.avg_latency_millis,
+                        attempt_count: persisted.attempt_count,
+                        failure_count: persisted.failure_count,
+                        ..Default::default()
+                    },
+                )
+            })
+            .collect();
+
         Self {
             transactions_fetcher: Arc::new(transactions_fetcher),
             watcher_db,
             store_block_data,
+            quorum_threshold: quorum_threshold.max(1),
+            allowed_signer_keys,
+            source_health: Arc::new(Mutex::new(source_health)),
+            pending_tallies: Arc::new(Mutex::new(HashMap::default())),
+            resolved_blocks: Arc::new(Mutex::new(HashMap::default())),
+            recorded_divergences: Arc::new(Mutex::new(HashSet::default())),
             logger,
         }
     }
 
+    /// Get recorded divergence events (sources disagreeing about a block's
+    /// contents or signer) for block indices in `range`.
+    pub fn get_divergences(&self, range: Range<u64>) -> Result<Vec<DivergenceEvent>, WatcherError> {
+        Ok(self.watcher_db.get_divergences(range)?)
+    }
+
+    /// Source urls currently quarantined due to repeated signature
+    /// verification failures, along with the instant each quarantine lifts.
+    pub fn quarantined_sources(&self) -> Vec<(Url, Instant)> {
+        let now = Instant::now();
+        let source_health = self
+            .source_health
+            .lock()
+            .expect("source_health lock poisoned");
+        source_health
+            .iter()
+            .filter_map(|(src_url, health)| {
+                health
+                    .quarantined_until
+                    .filter(|until| *until > now)
+                    .map(|until| (src_url.clone(), until))
+            })
+            .collect()
+    }
+
+    /// Current consecutive signature-verification failure count for each
+    /// source url the watcher holds health state for; zero for a source
+    /// whose most recent signature verified.
+    pub fn source_failure_counts(&self) -> HashMap<Url, u32> {
+        let source_health = self
+            .source_health
+            .lock()
+            .expect("source_health lock poisoned");
+        source_health
+            .iter()
+            .map(|(src_url, health)| (src_url.clone(), health.consecutive_failures))
+            .collect()
+    }
+
+    /// Observed reliability and performance metrics for each source url that
+    /// has been fetched from at least once, used to route fetch traffic
+    /// away from slow or flaky archive mirrors.
+    pub fn source_health(&self) -> HashMap<Url, SourceMetrics> {
+        let source_health = self
+            .source_health
+            .lock()
+            .expect("source_health lock poisoned");
+        let now = Instant::now();
+        source_health
+            .iter()
+            .map(|(src_url, health)| {
+                (
+                    src_url.clone(),
+                    SourceMetrics {
+                        avg_latency_millis: health.avg_latency_millis,
+                        success_rate: health.success_rate(),
+                        recent_error_count: health.recent_error_count,
+                        backoff_until: health.backoff_until.filter(|until| *until > now),
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// Block indices this watcher currently considers unresolved (not yet
+    /// backed by `quorum_threshold` agreement) at which sources disagreed,
+    /// read straight from the in-memory cross-round tally rather than the
+    /// DB. O(number of currently-pending indices), independent of chain
+    /// height.
+    fn unresolved_block_indices(&self) -> Vec<u64> {
+        self.pending_tallies
+            .lock()
+            .expect("pending_tallies lock poisoned")
+            .iter()
+            .filter(|(_, tally)| tally.is_divergent())
+            .map(|(block_index, _)| *block_index)
+            .collect()
+    }
+
+    /// Build a structured status snapshot of this watcher, measured against
+    /// `ledger_num_blocks`. Intended for an operator-facing status RPC or
+    /// monitoring endpoint, so this needs to stay cheap even when called on
+    /// every scrape of a monitoring dashboard regardless of chain height.
+    pub fn status(&self, ledger_num_blocks: u64) -> Result<WatcherStatus, WatcherError> {
+        let last_synced_blocks = self.watcher_db.last_synced_blocks()?;
+        let lowest_next_block_to_sync = self.lowest_next_block_to_sync()?;
+        let source_urls = HashSet::from_iter(self.transactions_fetcher.source_urls.iter().cloned());
+        let quarantined_sources = self
+            .quarantined_sources()
+            .into_iter()
+            .map(|(src_url, _until)| src_url)
+            .collect();
+
+        // Only scan the DB for divergences in a recent window, so the cost of a
+        // status call doesn't grow unboundedly with chain height; anything older
+        // that's still unresolved is already tracked in-memory and folded in below.
+        let scan_ceiling = lowest_next_block_to_sync.max(ledger_num_blocks).max(1);
+        let scan_range = scan_ceiling.saturating_sub(STATUS_DIVERGENCE_SCAN_WINDOW)..scan_ceiling;
+        let mut diverged_block_indices: HashSet<u64> = self
+            .get_divergences(scan_range)?
+            .iter()
+            .map(|event| event.block_index)
+            .collect();
+        diverged_block_indices.extend(self.unresolved_block_indices());
+
+        let mut diverged_block_indices: Vec<u64> = diverged_block_indices.into_iter().collect();
+        diverged_block_indices.sort_unstable();
+
+        Ok(WatcherStatus {
+            last_synced_blocks,
+            source_urls,
+            lowest_next_block_to_sync,
+            ledger_num_blocks,
+            is_behind: lowest_next_block_to_sync < ledger_num_blocks,
+            quarantined_sources,
+            source_health: self.source_health(),
+            diverged_block_indices,
+            db_version: self.watcher_db.version()?,
+        })
+    }
+
+    /// Verify a fetched block's signature, if any, was produced by one of
+    /// the configured consensus signer keys.
+    fn verify_signature(&self, block_data: &BlockData) -> Result<(), WatcherError> {
+        let signature = match block_data.signature() {
+            Some(signature) => signature,
+            None => return Ok(()),
+        };
+
+        if !self.allowed_signer_keys.contains(signature.signer()) {
+            return Err(WatcherError::UnknownSigner);
+        }
+
+        signature
+            .verify(block_data.block())
+            .map_err(|_| WatcherError::SignatureVerification)
+    }
+
+    /// Fold the latency and success/failure of each fetch into this source's
+    /// rolling metrics, growing its backoff window exponentially on repeated
+    /// errors. Returns the results with the per-fetch latency stripped, since
+    /// downstream signature verification and tallying don't need it.
+    fn record_fetch_metrics(
+        &self,
+        url_to_block_data_result: HashMap<
+            Url,
+            Vec<(u64, Duration, Result<BlockData, WatcherError>)>,
+        >,
+    ) -> HashMap<Url, Vec<(u64, Result<BlockData, WatcherError>)>> {
+        let mut source_health = self
+            .source_health
+            .lock()
+            .expect("source_health lock poisoned");
+
+        url_to_block_data_result
+            .into_iter()
+            .map(|(src_url, block_results)| {
+                let block_results = block_results
+                    .into_iter()
+                    .map(|(block_index, latency, block_data_result)| {
+                        let health = source_health.entry(src_url.clone()).or_default();
+                        match &block_data_result {
+                            Ok(_) => health.record_success(latency),
+                            Err(_) => health.record_failure(),
+                        }
+                        (block_index, block_data_result)
+                    })
+                    .collect();
+
+                (src_url, block_results)
+            })
+            .collect()
+    }
+
+    /// Persist each source's current rolling metrics so a restarted watcher
+    /// starts from what was previously observed instead of from scratch.
+    /// Best-effort: a persistence failure is logged but does not interrupt
+    /// syncing.
+    fn persist_source_metrics(&self) {
+        let source_health = self
+            .source_health
+            .lock()
+            .expect("source_health lock poisoned");
+        for (src_url, health) in source_health.iter() {
+            let persisted = PersistedSourceMetrics {
+                avg_latency_millis: health.avg_latency_millis,
+                attempt_count: health.attempt_count,
+                failure_count: health.failure_count,
+            };
+            if let Err(err) = self.watcher_db.update_source_metrics(src_url, &persisted) {
+                log::warn!(
+                    self.logger,
+                    "Failed persisting source metrics for {:?}: {:?}",
+                    src_url,
+                    err,
+                );
+            }
+        }
+    }
+
+    /// Verify each fetched block's signature against the configured signer
+    /// keys, updating per-source failure counters and quarantining sources
+    /// that cross `QUARANTINE_FAILURE_THRESHOLD`. Blocks whose signature
+    /// fails verification are downgraded to an error so they are never
+    /// tallied or stored.
+    fn verify_and_filter(
+        &self,
+        url_to_block_data_result: HashMap<Url, Vec<(u64, Result<BlockData, WatcherError>)>>,
+    ) -> HashMap<Url, Vec<(u64, Result<BlockData, WatcherError>)>> {
+        let mut source_health = self
+            .source_health
+            .lock()
+            .expect("source_health lock poisoned");
+
+        url_to_block_data_result
+            .into_iter()
+            .map(|(src_url, block_results)| {
+                let block_results = block_results
+                    .into_iter()
+                    .map(|(block_index, block_data_result)| {
+                        let block_data_result = block_data_result.and_then(|block_data| match self
+                            .verify_signature(&block_data)
+                        {
+                            Ok(()) => {
+                                source_health
+                                    .entry(src_url.clone())
+                                    .or_default()
+                                    .consecutive_failures = 0;
+                                Ok(block_data)
+                            }
+                            Err(err) => {
+                                log::warn!(
+                                    self.logger,
+                                    "Signature verification failed for {:?} block {}: {:?}",
+                                    src_url,
+                                    block_index,
+                                    err,
+                                );
+
+                                let health = source_health.entry(src_url.clone()).or_default();
+                                health.consecutive_failures += 1;
+                                if health.consecutive_failures >= QUARANTINE_FAILURE_THRESHOLD {
+                                    health.quarantined_until =
+                                        Some(Instant::now() + QUARANTINE_BACKOFF);
+                                    log::warn!(
+                                        self.logger,
+                                        "Quarantining {:?} after {} consecutive signature failures",
+                                        src_url,
+                                        health.consecutive_failures,
+                                    );
+                                }
+
+                                Err(err)
+                            }
+                        });
+
+                        (block_index, block_data_result)
+                    })
+                    .collect();
+
+                (src_url, block_results)
+            })
+            .collect()
+    }
+
+    /// Persist a single source's block data for `block_index`: the raw block
+    /// data (if `store_block_data` is set), then its signature, or a bare
+    /// last-synced marker for an unsigned block. Tolerates `add_block_data`
+    /// seeing the same `(src_url, block_index)` pair more than once (e.g. a
+    /// source that catches up to an already-resolved index across two
+    /// rounds), since that call already treats `AlreadyExists` as success.
+    fn commit_block(
+        &self,
+        src_url: &Url,
+        block_index: u64,
+        block_data: &BlockData,
+    ) -> Result<(), WatcherError> {
+        if self.store_block_data {
+            match self.watcher_db.add_block_data(src_url, block_data) {
+                Ok(()) => {}
+                Err(WatcherDBError::AlreadyExists) => {}
+                Err(err) => return Err(err.into()),
+            };
+        }
+
+        if let Some(signature) = block_data.signature() {
+            let filename = block_num_to_s3block_path(block_index)
+                .into_os_string()
+                .into_string()
+                .unwrap();
+            self.watcher_db.add_block_signature(
+                src_url,
+                block_index,
+                signature.clone(),
+                filename,
+            )?;
+        } else {
+            self.watcher_db.update_last_synced(src_url, block_index)?;
+        }
+
+        Ok(())
+    }
+
     /// The lowest next block we need to try and sync.
     pub fn lowest_next_block_to_sync(&self) -> Result<u64, WatcherError> {
         let last_synced = self.watcher_db.last_synced_blocks()?;
@@ -118,76 +819,342 @@ impl Watcher {
                         .unwrap_or(true)
                 });
             }
+
+            // Exclude any source currently quarantined for repeated signature
+            // verification failures, or backed off after repeated fetch errors; it
+            // will be retried once its window elapses, routing work toward whatever
+            // other sources remain.
+            {
+                let now = Instant::now();
+                let source_health = self
+                    .source_health
+                    .lock()
+                    .expect("source_health lock poisoned");
+                let had_sources_to_sync = !last_synced.is_empty();
+                last_synced.retain(|src_url, _| {
+                    source_health
+                        .get(src_url)
+                        .map(|health| {
+                            health
+                                .quarantined_until
+                                .map(|until| now >= until)
+                                .unwrap_or(true)
+                                && health
+                                    .backoff_until
+                                    .map(|until| now >= until)
+                                    .unwrap_or(true)
+                        })
+                        .unwrap_or(true)
+                });
+
+                // Blocks remain to be synced, but every source that still has them
+                // is quarantined or backed off for now.
+                if had_sources_to_sync && last_synced.is_empty() {
+                    return Ok(false);
+                }
+            }
+
             if last_synced.is_empty() {
                 return Ok(true);
             }
 
             // Construct a map of src_url -> next block index we want to attempt to sync.
+            // A source that is still disputing an already-resolved index (see
+            // `resolved_blocks`) is simply asked for its own next index like any
+            // other source; it is never dragged back to re-litigate an index the
+            // rest of the sources have already moved past.
             let url_to_block_index: HashMap<Url, u64> = last_synced
                 .iter()
                 .map(|(src_url, opt_block_index)| {
-                    (
-                        src_url.clone(),
-                        opt_block_index.map(|i| i + 1).unwrap_or(start),
-                    )
+                    let next_index = opt_block_index.map(|i| i + 1).unwrap_or(start);
+                    (src_url.clone(), next_index)
                 })
                 .collect();
 
-            // Attempt to fetch block data for all urls in parallel.
-            let url_to_block_data_result =
-                parallel_fetch_blocks(url_to_block_index, self.transactions_fetcher.clone())?;
+            // Prefer fast/reliable sources for the leading edge: scale each source's
+            // pipeline depth by its rolling success rate, and throttle any source with
+            // an error since its last success down to a single in-flight probe, so
+            // fetch capacity is routed toward whichever sources are actually keeping
+            // up and a flaky mirror can't hold up the round pipelining as deeply as a
+            // healthy one.
+            let url_to_fetch_window: HashMap<Url, usize> = {
+                let source_health = self
+                    .source_health
+                    .lock()
+                    .expect("source_health lock poisoned");
+                url_to_block_index
+                    .keys()
+                    .map(|src_url| {
+                        let window = source_health
+                            .get(src_url)
+                            .map(|health| health.preferred_fetch_window(FETCH_WINDOW))
+                            .unwrap_or(FETCH_WINDOW);
+                        (src_url.clone(), window)
+                    })
+                    .collect()
+            };
+
+            // Attempt to fetch block data for all urls in parallel, pipelining up to
+            // each source's preferred window of outstanding requests across the range
+            // instead of fetching one block per source at a time.
+            // `max_block_height` is inclusive, while the fetch range end is exclusive.
+            let range_ceiling = max_block_height.map_or(u64::MAX, |max| max.saturating_add(1));
+            let url_to_block_data_result = parallel_fetch_blocks(
+                url_to_block_index,
+                url_to_fetch_window,
+                range_ceiling,
+                self.transactions_fetcher.clone(),
+            )?;
 
-            // Store data for each successfully synced blocked. Track on whether any of the
-            // sources was able to produce block data. If so, more data might be
-            // available.
+            // Fold the observed latency and success/failure of each fetch into this
+            // source's adaptive routing metrics before anything else looks at the
+            // results.
+            let url_to_block_data_result = self.record_fetch_metrics(url_to_block_data_result);
+            self.persist_source_metrics();
+
+            // Verify each fetched block's signature before trusting it any further.
+            let url_to_block_data_result = self.verify_and_filter(url_to_block_data_result);
+
+            // Track whether any of the sources was able to produce block data. If so,
+            // more data might be available.
             let mut had_success = false;
 
-            for (src_url, (block_index, block_data_result)) in url_to_block_data_result.iter() {
-                match block_data_result {
-                    Ok(block_data) => {
-                        log::info!(
-                            self.logger,
-                            "Archive block retrieved for {:?} {:?}",
-                            src_url,
-                            block_index
-                        );
-                        if self.store_block_data {
-                            match self.watcher_db.add_block_data(src_url, &block_data) {
-                                Ok(()) => {}
-                                Err(WatcherDBError::AlreadyExists) => {}
-                                Err(err) => {
-                                    return Err(err.into());
+            // Observe the block contents and signer reported by each source this
+            // round, grouped by block index, so we can compare sources against each
+            // other before committing anything to the DB. A source may have returned
+            // a whole window of blocks this round, so we flatten and re-sort by index
+            // first to keep commits in index order. Observations are merged into
+            // `pending_tallies` rather than a fresh per-round map so a block index
+            // keeps accumulating sources' reports across rounds even after some of
+            // those sources have moved on to later indices.
+            //
+            // If an index already resolved (see `resolved_blocks`), a source
+            // reporting on it now is either catching up (it agrees with the
+            // confirmed content, so it can just be committed directly) or still
+            // disagreeing (logged once via `recorded_divergences`, but otherwise
+            // left alone) — either way it does not reopen the index as pending.
+            let mut block_data_by_key: HashMap<(Url, u64), &BlockData> = HashMap::default();
+            let mut touched_indices: HashSet<u64> = HashSet::default();
+            let mut catch_up_commits: Vec<(&Url, u64, &BlockData)> = Vec::new();
+            let mut straggler_divergences: Vec<(u64, &Url, BlockID, Option<Vec<u8>>)> = Vec::new();
+
+            {
+                let mut pending_tallies = self
+                    .pending_tallies
+                    .lock()
+                    .expect("pending_tallies lock poisoned");
+                let resolved_blocks = self
+                    .resolved_blocks
+                    .lock()
+                    .expect("resolved_blocks lock poisoned");
+                let mut recorded_divergences = self
+                    .recorded_divergences
+                    .lock()
+                    .expect("recorded_divergences lock poisoned");
+
+                for (src_url, block_results) in url_to_block_data_result.iter() {
+                    for (block_index, block_data_result) in block_results.iter() {
+                        match block_data_result {
+                            Ok(block_data) => {
+                                log::info!(
+                                    self.logger,
+                                    "Archive block retrieved for {:?} {:?}",
+                                    src_url,
+                                    block_index
+                                );
+                                had_success = true;
+
+                                let content_id = block_data.block().id.clone();
+
+                                match resolved_blocks.get(block_index) {
+                                    Some(confirmed_content_id)
+                                        if confirmed_content_id == &content_id =>
+                                    {
+                                        catch_up_commits.push((src_url, *block_index, block_data));
+                                    }
+                                    Some(_) => {
+                                        if recorded_divergences
+                                            .insert((*block_index, src_url.clone()))
+                                        {
+                                            let signer = block_data.signature().map(|signature| {
+                                                signature.signer().to_bytes().to_vec()
+                                            });
+                                            straggler_divergences.push((
+                                                *block_index,
+                                                src_url,
+                                                content_id,
+                                                signer,
+                                            ));
+                                        }
+                                    }
+                                    None => {
+                                        let signer = block_data.signature().map(|signature| {
+                                            signature.signer().to_bytes().to_vec()
+                                        });
+                                        pending_tallies.entry(*block_index).or_default().observe(
+                                            src_url.clone(),
+                                            content_id,
+                                            signer,
+                                        );
+
+                                        block_data_by_key
+                                            .insert((src_url.clone(), *block_index), block_data);
+                                        touched_indices.insert(*block_index);
+                                    }
                                 }
-                            };
+                            }
+
+                            Err(err) => {
+                                log::debug!(
+                                    self.logger,
+                                    "Could not sync block {} for url ({:?})",
+                                    block_index,
+                                    err
+                                );
+                            }
                         }
+                    }
+                }
+            }
 
-                        if let Some(signature) = block_data.signature() {
-                            let filename = block_num_to_s3block_path(*block_index)
-                                .into_os_string()
-                                .into_string()
-                                .unwrap();
-                            self.watcher_db.add_block_signature(
-                                src_url,
+            for (block_index, src_url, content_id, signer) in straggler_divergences {
+                log::warn!(
+                    self.logger,
+                    "{:?} still disagrees with the confirmed contents of block {}",
+                    src_url,
+                    block_index,
+                );
+                self.watcher_db
+                    .record_divergence(block_index, src_url, &content_id, signer)?;
+            }
+
+            for (src_url, block_index, block_data) in catch_up_commits {
+                self.commit_block(src_url, block_index, block_data)?;
+            }
+
+            // Compare sources against each other for every block index touched this
+            // round, record any divergence, and only commit the quorum value once
+            // enough sources have agreed on it (using everything accumulated for that
+            // index so far, not just this round's reports). Indices are processed in
+            // increasing order so that, per source, `update_last_synced`/
+            // `add_block_signature` keep advancing monotonically even though
+            // `touched_indices` is an unordered set.
+            let mut sorted_indices: Vec<u64> = touched_indices.into_iter().collect();
+            sorted_indices.sort_unstable();
+
+            for block_index in sorted_indices.iter() {
+                let mut pending_tallies = self
+                    .pending_tallies
+                    .lock()
+                    .expect("pending_tallies lock poisoned");
+                let tally = match pending_tallies.get(block_index) {
+                    Some(tally) => tally.clone(),
+                    None => continue,
+                };
+                drop(pending_tallies);
+
+                if tally.is_divergent() {
+                    let mut recorded_divergences = self
+                        .recorded_divergences
+                        .lock()
+                        .expect("recorded_divergences lock poisoned");
+
+                    for (content_id, urls) in &tally.by_content {
+                        for src_url in urls {
+                            if !recorded_divergences.insert((*block_index, src_url.clone())) {
+                                continue;
+                            }
+
+                            log::warn!(
+                                self.logger,
+                                "Divergence detected at block {}: {} distinct contents, {} distinct signers",
+                                block_index,
+                                tally.by_content.len(),
+                                tally.by_signer.len(),
+                            );
+
+                            self.watcher_db.record_divergence(
                                 *block_index,
-                                signature.clone(),
-                                filename,
+                                src_url,
+                                content_id,
+                                tally
+                                    .by_signer
+                                    .iter()
+                                    .find(|(_, signer_urls)| signer_urls.contains(src_url))
+                                    .map(|(signer, _)| signer.clone()),
                             )?;
-                        } else {
-                            self.watcher_db.update_last_synced(src_url, *block_index)?;
                         }
-
-                        had_success = true;
                     }
+                }
 
-                    Err(err) => {
-                        log::debug!(
-                            self.logger,
-                            "Could not sync block {} for url ({:?})",
-                            block_index,
-                            err
-                        );
+                let (quorum_content_id, quorum_count) = match tally.quorum_content() {
+                    Some(value) => value,
+                    None => continue,
+                };
+
+                if quorum_count < self.quorum_threshold {
+                    log::debug!(
+                        self.logger,
+                        "Block {} pending: only {} of {} required sources agree",
+                        block_index,
+                        quorum_count,
+                        self.quorum_threshold,
+                    );
+                    continue;
+                }
+
+                // Enough sources agree on `quorum_content_id` to confirm this block.
+                // Only commit for sources we actually have fresh block data for this
+                // round; a source that contributed to the winning tally in an earlier
+                // round but hasn't been re-polled yet will be committed once it
+                // responds again. Skip any source that's already synced past this
+                // index (it was already committed in an earlier round), so a tally
+                // entry that's momentarily re-observed can't re-invoke
+                // `add_block_signature`/`update_last_synced` for a pair already
+                // committed.
+                let mut all_committed = true;
+                for src_url in &tally.by_content[quorum_content_id] {
+                    let already_committed = last_synced
+                        .get(src_url)
+                        .and_then(|opt_index| *opt_index)
+                        .map_or(false, |synced_index| synced_index >= *block_index);
+                    if already_committed {
+                        continue;
                     }
+
+                    let block_data = match block_data_by_key.get(&(src_url.clone(), *block_index)) {
+                        Some(block_data) => *block_data,
+                        None => {
+                            all_committed = false;
+                            continue;
+                        }
+                    };
+
+                    self.commit_block(src_url, *block_index, block_data)?;
+                }
+
+                // Keep the index pending until every source in the winning tally has
+                // committed it, so a source without fresh data this round is committed
+                // when it reports the index again.
+                if !all_committed {
+                    continue;
                 }
+
+                // This index is resolved: drop it from the cross-round tally so it
+                // doesn't keep being re-polled for or re-checked once every source
+                // that matters has committed it. If it was ever disputed, remember
+                // its confirmed content so a source that keeps disagreeing after the
+                // fact is recognized without resurrecting the index as pending.
+                if tally.is_divergent() {
+                    self.resolved_blocks
+                        .lock()
+                        .expect("resolved_blocks lock poisoned")
+                        .insert(*block_index, quorum_content_id.clone());
+                }
+                self.pending_tallies
+                    .lock()
+                    .expect("pending_tallies lock poisoned")
+                    .remove(block_index);
             }
 
             // If nothing succeeded, maybe we are synced all the way through or something
@@ -199,25 +1166,45 @@ impl Watcher {
     }
 }
 
-/// A naive implementation for fetching blocks from multiple source urls
-/// concurrently. It is naive in the sense that is spawns one thread per source
-/// URL, which in theory does not scale but in reality we do not expect a large
-/// number of sources.
+/// Upper bound on the number of block requests kept outstanding at once per
+/// source url when pipelining a range fetch. Bounds memory/thread use while
+/// still overlapping round-trips, which matters a lot against high-latency
+/// S3 endpoints. A given source's actual window may be throttled below this
+/// (see `SourceHealth::preferred_fetch_window`) when it's been flaky.
+const FETCH_WINDOW: usize = 8;
+
+/// Fetch blocks concurrently from multiple source urls. Spawns one thread
+/// per source url (we do not expect a large number of sources) and, within
+/// each source, pipelines up to `url_to_fetch_window[src_url]` outstanding
+/// block requests across `[url_to_block_index[src_url], range_ceiling)`
+/// instead of fetching a single block per source per call.
 fn parallel_fetch_blocks(
     url_to_block_index: HashMap<Url, u64>,
+    url_to_fetch_window: HashMap<Url, usize>,
+    range_ceiling: u64,
     transactions_fetcher: Arc<ReqwestTransactionsFetcher>,
-) -> Result<HashMap<Url, (u64, Result<BlockData, WatcherError>)>, WatcherError> {
+) -> Result<HashMap<Url, Vec<(u64, Duration, Result<BlockData, WatcherError>)>>, WatcherError> {
     let join_handles = url_to_block_index
         .into_iter()
-        .map(|(src_url, block_index)| {
+        .map(|(src_url, start)| {
             let transactions_fetcher = transactions_fetcher.clone();
+            let thread_src_url = src_url.clone();
+            let fetch_window = url_to_fetch_window
+                .get(&src_url)
+                .copied()
+                .unwrap_or(FETCH_WINDOW);
 
             thread::Builder::new()
                 .name("ParallelFetch".into())
                 .spawn(move || {
-                    let block_fetch_result =
-                        fetch_single_block(transactions_fetcher, &src_url, block_index);
-                    (src_url, (block_index, block_fetch_result))
+                    let results = pipelined_fetch_range(
+                        transactions_fetcher,
+                        thread_src_url,
+                        start,
+                        range_ceiling,
+                        fetch_window,
+                    );
+                    (src_url, results)
                 })
                 .expect("Failed spawning ParallelFetch thread")
         })
@@ -230,6 +1217,70 @@ fn parallel_fetch_blocks(
     ))
 }
 
+/// Fetch blocks `[start, range_ceiling)` from a single source url, keeping
+/// up to `fetch_window` requests outstanding at once (see
+/// `SourceHealth::preferred_fetch_window` for how callers pick this).
+/// Requests are issued in increasing block index order and `in_flight` is a
+/// FIFO queue, so popping its front and joining always yields the next index
+/// in sequence — there's no need to buffer completions for out-of-order
+/// arrival. Once a fetch fails, stops issuing new requests and returns
+/// immediately, so the returned vector is always a contiguous prefix
+/// starting at `start` with no gaps for the caller to silently skip over.
+/// Requests still in flight at that point are detached rather than awaited;
+/// they run to completion in the background with their results simply
+/// discarded.
+fn pipelined_fetch_range(
+    transactions_fetcher: Arc<ReqwestTransactionsFetcher>,
+    src_url: Url,
+    start: u64,
+    range_ceiling: u64,
+    fetch_window: usize,
+) -> Vec<(u64, Duration, Result<BlockData, WatcherError>)> {
+    let mut next_to_issue = start;
+    let mut in_flight: VecDeque<
+        thread::JoinHandle<(u64, Duration, Result<BlockData, WatcherError>)>,
+    > = VecDeque::new();
+    let mut results = Vec::new();
+
+    loop {
+        while in_flight.len() < fetch_window && next_to_issue < range_ceiling {
+            let transactions_fetcher = transactions_fetcher.clone();
+            let src_url = src_url.clone();
+            let block_index = next_to_issue;
+
+            in_flight.push_back(
+                thread::Builder::new()
+                    .name("ParallelFetch".into())
+                    .spawn(move || {
+                        let fetch_started = Instant::now();
+                        let result =
+                            fetch_single_block(transactions_fetcher, &src_url, block_index);
+                        (block_index, fetch_started.elapsed(), result)
+                    })
+                    .expect("Failed spawning ParallelFetch thread"),
+            );
+            next_to_issue += 1;
+        }
+
+        let handle = match in_flight.pop_front() {
+            Some(handle) => handle,
+            None => break,
+        };
+
+        let (block_index, latency, result) = handle.join().expect("Thread join failed");
+        let failed = result.is_err();
+        results.push((block_index, latency, result));
+
+        if failed {
+            // Drop the rest of the in-flight handles without joining them; the
+            // threads keep running but we stop waiting on (and reporting) them.
+            return results;
+        }
+    }
+
+    results
+}
+
 /// A helper for fetching a single block (identified by a given block index)
 /// from some source url.
 fn fetch_single_block(
@@ -246,8 +1297,11 @@ fn fetch_single_block(
     Ok(transactions_fetcher.block_from_url(&block_url)?)
 }
 
-/// Maximal number of blocks to attempt to sync at each loop iteration.
-const MAX_BLOCKS_PER_SYNC_ITERATION: u32 = 10;
+/// Maximal number of blocks to attempt to sync at each loop iteration. Now
+/// that `parallel_fetch_blocks` pipelines a window of requests per source
+/// instead of one block per round-trip, this can safely cover a much larger
+/// backlog per iteration.
+const MAX_BLOCKS_PER_SYNC_ITERATION: u32 = 1000;
 
 /// Syncs new ledger materials for the watcher when the local ledger
 /// appends new blocks.
@@ -255,31 +1309,55 @@ pub struct WatcherSyncThread {
     join_handle: Option<thread::JoinHandle<()>>,
     currently_behind: Arc<AtomicBool>,
     stop_requested: Arc<AtomicBool>,
+    caught_up_subscribers: Arc<Mutex<Vec<Sender<()>>>>,
 }
 
 impl WatcherSyncThread {
     /// Create a new watcher sync thread.
+    ///
+    /// # Arguments
+    /// * `caught_up_delta` - How close `lowest_next_block_to_sync` must be to
+    ///   `ledger.num_blocks()` to be considered caught up, for the purposes
+    ///   of `caught_up_once` and `subscribe_caught_up`. Once within this
+    ///   delta the thread also stops spinning and falls back to sleeping
+    ///   `poll_interval` between sync attempts.
+    /// * `caught_up_once` - If given, signaled exactly once, the first time
+    ///   the watcher comes within `caught_up_delta` of the ledger's height.
+    ///   Lets a caller (e.g. an RPC that must not answer until the watcher
+    ///   is fresh) await readiness instead of spinning on `is_behind`.
+    /// * `quorum_threshold` - The number of sources that must agree on a
+    ///   block's contents before it is confirmed. See
+    ///   `Watcher::with_quorum_threshold`.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         watcher_db: WatcherDB,
         transactions_fetcher: ReqwestTransactionsFetcher,
         ledger: impl Ledger + 'static,
         poll_interval: Duration,
         store_block_data: bool,
+        allowed_signer_keys: HashSet<Ed25519Public>,
+        quorum_threshold: usize,
+        caught_up_delta: u64,
+        caught_up_once: Option<Sender<()>>,
         logger: Logger,
     ) -> Self {
         log::debug!(logger, "Creating watcher sync thread.");
-        let watcher = Watcher::new(
+        let watcher = Watcher::with_quorum_threshold(
             watcher_db,
             transactions_fetcher,
             store_block_data,
+            allowed_signer_keys,
+            quorum_threshold,
             logger.clone(),
         );
 
         let currently_behind = Arc::new(AtomicBool::new(false));
         let stop_requested = Arc::new(AtomicBool::new(false));
+        let caught_up_subscribers: Arc<Mutex<Vec<Sender<()>>>> = Arc::new(Mutex::new(Vec::new()));
 
         let thread_currently_behind = currently_behind.clone();
         let thread_stop_requested = stop_requested.clone();
+        let thread_caught_up_subscribers = caught_up_subscribers.clone();
         let join_handle = Some(
             thread::Builder::new()
                 .name("WatcherSync".into())
@@ -288,8 +1366,11 @@ impl WatcherSyncThread {
                         ledger,
                         watcher,
                         poll_interval,
+                        caught_up_delta,
+                        caught_up_once,
                         thread_currently_behind,
                         thread_stop_requested,
+                        thread_caught_up_subscribers,
                         logger,
                     );
                 })
@@ -300,6 +1381,7 @@ impl WatcherSyncThread {
             join_handle,
             currently_behind,
             stop_requested,
+            caught_up_subscribers,
         }
     }
 
@@ -316,17 +1398,38 @@ impl WatcherSyncThread {
         self.currently_behind.load(Ordering::SeqCst)
     }
 
+    /// Subscribe to behind → caught-up transitions. Returns a receiver
+    /// that gets a message every time the watcher goes from being more than
+    /// `caught_up_delta` blocks behind the ledger to being within it, e.g.
+    /// after catching up from a cold start, or after falling behind again
+    /// and re-catching-up.
+    pub fn subscribe_caught_up(&self) -> Receiver<()> {
+        let (sender, receiver) = mpsc::channel();
+        self.caught_up_subscribers
+            .lock()
+            .expect("caught_up_subscribers lock poisoned")
+            .push(sender);
+        receiver
+    }
+
     /// The entrypoint for the watcher sync thread.
     fn thread_entrypoint(
         ledger: impl Ledger,
         watcher: Watcher,
         poll_interval: Duration,
+        caught_up_delta: u64,
+        mut caught_up_once: Option<Sender<()>>,
         currently_behind: Arc<AtomicBool>,
         stop_requested: Arc<AtomicBool>,
+        caught_up_subscribers: Arc<Mutex<Vec<Sender<()>>>>,
         logger: Logger,
     ) {
         log::debug!(logger, "WatcherSyncThread has started.");
 
+        // Whether we were within `caught_up_delta` of the ledger's height as of
+        // the previous iteration, used to detect behind -> caught-up transitions.
+        let mut was_caught_up = false;
+
         loop {
             if stop_requested.load(Ordering::SeqCst) {
                 log::debug!(logger, "WatcherSyncThread stop requested.");
@@ -339,6 +1442,10 @@ impl WatcherSyncThread {
             let ledger_num_blocks = ledger.num_blocks().unwrap();
             // See if we're currently behind.
             let is_behind = { lowest_next_block_to_sync < ledger_num_blocks };
+            // See if we're close enough to the tip to stop spinning and start
+            // sleeping between attempts, and to consider ourselves "caught up" for
+            // the purposes of the completion signals below.
+            let is_caught_up = lowest_next_block_to_sync + caught_up_delta >= ledger_num_blocks;
             log::debug!(
                 logger,
                 "Lowest next block to sync: {}, Ledger block height {}, is_behind {}",
@@ -359,7 +1466,25 @@ impl WatcherSyncThread {
                 );
             }
 
-            // Maybe sync, maybe wait and check again.
+            if is_caught_up {
+                if let Some(sender) = caught_up_once.take() {
+                    log::info!(logger, "Watcher has caught up for the first time.");
+                    let _ = sender.send(());
+                }
+
+                if !was_caught_up {
+                    log::info!(logger, "Watcher transitioned from behind to caught up.");
+                    let subscribers = caught_up_subscribers
+                        .lock()
+                        .expect("caught_up_subscribers lock poisoned");
+                    for subscriber in subscribers.iter() {
+                        let _ = subscriber.send(());
+                    }
+                }
+            }
+            was_caught_up = is_caught_up;
+
+            // Sync if behind.
             if is_behind {
                 let max_blocks = std::cmp::min(
                     ledger_num_blocks - 1,
@@ -368,7 +1493,11 @@ impl WatcherSyncThread {
                 watcher
                     .sync_blocks(lowest_next_block_to_sync, Some(max_blocks))
                     .expect("Could not sync blocks");
-            } else if !stop_requested.load(Ordering::SeqCst) {
+            }
+
+            // Keep looping sync_blocks without sleeping while a large backlog
+            // remains; only fall back to polling once we're genuinely near the tip.
+            if is_caught_up && !stop_requested.load(Ordering::SeqCst) {
                 log::trace!(
                     logger,
                     "Sleeping, watcher blocks synced = {}...",
@@ -385,3 +1514,42 @@ impl Drop for WatcherSyncThread {
         self.stop();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn healthy_source_gets_full_fetch_window() {
+        let health = SourceHealth::default();
+        assert_eq!(health.preferred_fetch_window(FETCH_WINDOW), FETCH_WINDOW);
+    }
+
+    #[test]
+    fn source_with_a_recent_error_is_throttled_to_a_single_probe() {
+        let health = SourceHealth {
+            recent_error_count: 1,
+            attempt_count: 10,
+            failure_count: 1,
+            ..Default::default()
+        };
+        assert_eq!(
+            health.preferred_fetch_window(FETCH_WINDOW),
+            MIN_FETCH_WINDOW
+        );
+    }
+
+    #[test]
+    fn unreliable_but_currently_succeeding_source_gets_a_scaled_down_window() {
+        let health = SourceHealth {
+            recent_error_count: 0,
+            attempt_count: 10,
+            failure_count: 5,
+            ..Default::default()
+        };
+        assert_eq!(
+            health.preferred_fetch_window(FETCH_WINDOW),
+            FETCH_WINDOW / 2
+        );
+    }
+}